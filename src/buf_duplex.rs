@@ -0,0 +1,143 @@
+//! A `Duplex` with both sides internally buffered.
+
+use async_std::io::{self, BufRead, BufReader, BufWriter, IntoInnerError, Read, Write};
+use async_std::task::{Context, Poll};
+use std::fmt;
+use std::pin::Pin;
+
+use crate::Duplex;
+
+pin_project_lite::pin_project! {
+    /// A [`Duplex`] whose reader and writer sides are both internally
+    /// buffered.
+    ///
+    /// This saves callers from manually nesting [`BufReader`] and
+    /// [`BufWriter`] around a raw reader and writer in the correct order.
+    pub struct BufDuplex<R, W> {
+        #[pin]
+        inner: Duplex<BufReader<R>, BufWriter<W>>,
+    }
+}
+
+impl<R, W> fmt::Debug for BufDuplex<R, W>
+where
+    R: Read + fmt::Debug,
+    W: Write + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BufDuplex").field("inner", &self.inner).finish()
+    }
+}
+
+impl<R, W> BufDuplex<R, W> {
+    /// Create a new `BufDuplex` with default buffer capacities.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> std::io::Result<()> { async_std::task::block_on(async {
+    /// #
+    /// use async_std::io::prelude::*;
+    /// use duplexify::BufDuplex;
+    ///
+    /// let (a, _b) = duplexify::Duplex::pipe(64);
+    /// let (reader, writer) = a.into_inner();
+    /// let mut stdio = BufDuplex::new(reader, writer);
+    /// stdio.write_all(b"hello").await?;
+    /// #
+    /// # Ok(()) }) }
+    /// ```
+    pub fn new(reader: R, writer: W) -> Self
+    where
+        R: Read,
+        W: Write,
+    {
+        Self {
+            inner: Duplex::new(BufReader::new(reader), BufWriter::new(writer)),
+        }
+    }
+
+    /// Create a new `BufDuplex` with the given reader and writer buffer
+    /// capacities.
+    pub fn with_capacity(read_capacity: usize, write_capacity: usize, reader: R, writer: W) -> Self
+    where
+        R: Read,
+        W: Write,
+    {
+        Self {
+            inner: Duplex::new(
+                BufReader::with_capacity(read_capacity, reader),
+                BufWriter::with_capacity(write_capacity, writer),
+            ),
+        }
+    }
+
+    /// Decomposes a `BufDuplex` into the original reader and writer.
+    ///
+    /// This flushes the writer's internal buffer. If the flush fails, the
+    /// returned [`IntoInnerError`] carries both the error and the buffered
+    /// writer, so the unwritten bytes aren't silently lost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> std::io::Result<()> { async_std::task::block_on(async {
+    /// #
+    /// use async_std::io::prelude::*;
+    /// use duplexify::BufDuplex;
+    ///
+    /// let (a, _b) = duplexify::Duplex::pipe(64);
+    /// let (reader, writer) = a.into_inner();
+    /// let mut stdio = BufDuplex::new(reader, writer);
+    /// stdio.write_all(b"hello").await?;
+    /// let (_reader, _writer) = stdio.into_inner().await?;
+    /// #
+    /// # Ok(()) }) }
+    /// ```
+    pub async fn into_inner(self) -> Result<(R, W), IntoInnerError<BufWriter<W>>>
+    where
+        R: Read,
+        W: Write + Unpin,
+    {
+        let (reader, writer) = self.inner.into_inner();
+        let writer = writer.into_inner().await?;
+        Ok((reader.into_inner(), writer))
+    }
+}
+
+impl<R: Read, W> Read for BufDuplex<R, W> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        self.project().inner.poll_read(cx, buf)
+    }
+}
+
+impl<R: Read, W> BufRead for BufDuplex<R, W> {
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        self.project().inner.poll_fill_buf(cx)
+    }
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        self.project().inner.consume(amt)
+    }
+}
+
+impl<R, W: Write> Write for BufDuplex<R, W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.project().inner.poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.project().inner.poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.project().inner.poll_close(cx)
+    }
+}
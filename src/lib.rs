@@ -44,6 +44,45 @@ use async_std::io::{self, BufRead, Read, Write};
 use async_std::task::{Context, Poll};
 use std::pin::Pin;
 
+mod buf_duplex;
+mod pipe;
+mod tapped_duplex;
+
+pub use buf_duplex::BufDuplex;
+pub use pipe::{PipeReader, PipeWriter};
+pub use tapped_duplex::TappedDuplex;
+
+/// A cooperative scheduling budget for a [`Duplex`].
+///
+/// Counts ready polls since the last forced yield and forces a single
+/// `Pending` once `budget` is reached, so a `Duplex` wrapping an always-ready source
+/// can't monopolize the executor.
+#[derive(Debug, Clone)]
+struct Coop {
+    budget: usize,
+    count: usize,
+}
+
+impl Coop {
+    /// Returns `true` if the budget is exhausted and the caller should
+    /// yield by returning `Poll::Pending` *without* polling the inner
+    /// reader/writer. Resets the counter and reschedules the task so
+    /// forward progress is still guaranteed.
+    fn should_yield(&mut self, cx: &mut Context<'_>) -> bool {
+        if self.count < self.budget {
+            return false;
+        }
+        self.count = 0;
+        cx.waker().wake_by_ref();
+        true
+    }
+
+    /// Record a completed ready poll.
+    fn record(&mut self) {
+        self.count += 1;
+    }
+}
+
 pin_project_lite::pin_project! {
     /// Combine a reader + writer into a duplex of `Read` + `Write`.
     #[derive(Debug)]
@@ -52,19 +91,108 @@ pin_project_lite::pin_project! {
         reader: R,
         #[pin]
         writer: W,
+        coop: Option<Coop>,
     }
 }
 
 impl<R, W> Duplex<R, W> {
     /// Create a new instance.
     pub fn new(reader: R, writer: W) -> Self {
-        Self { reader, writer }
+        Self {
+            reader,
+            writer,
+            coop: None,
+        }
+    }
+
+    /// Create a new instance with a cooperative scheduling budget.
+    ///
+    /// After every `budget` ready `poll_read`/`poll_write` calls, the
+    /// `Duplex` forces a single `Poll::Pending` (after rescheduling the
+    /// current task) before resuming. This keeps a `Duplex` wrapping an
+    /// always-ready source, such as an in-memory buffer, from starving
+    /// other tasks on the executor.
+    ///
+    /// Forcing a `Pending` never discards a byte that the inner reader or
+    /// writer already reported as read or written: the budget is checked
+    /// *before* polling the inner reader/writer, so a yield never throws
+    /// away a completed `Ready` result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `budget` is `0`: such a `Duplex` would yield on every poll
+    /// and never reach the inner reader or writer.
+    ///
+    /// # Examples
+    ///
+    /// Writing well past `budget` operations through the budgeted end loses
+    /// nothing and duplicates nothing, it just yields to the executor
+    /// periodically:
+    ///
+    /// ```
+    /// # fn main() -> std::io::Result<()> { async_std::task::block_on(async {
+    /// #
+    /// use async_std::io::prelude::*;
+    /// use duplexify::Duplex;
+    ///
+    /// let (a, mut b) = Duplex::pipe(1024);
+    /// let (reader, writer) = a.into_inner();
+    /// let mut a = Duplex::with_coop_budget(reader, writer, 4);
+    ///
+    /// let sent: Vec<u8> = (0..10).collect();
+    /// for &byte in &sent {
+    ///     a.write_all(&[byte]).await?;
+    /// }
+    ///
+    /// let mut received = vec![0; sent.len()];
+    /// b.read_exact(&mut received).await?;
+    /// assert_eq!(received, sent);
+    /// #
+    /// # Ok(()) }) }
+    /// ```
+    pub fn with_coop_budget(reader: R, writer: W, budget: usize) -> Self {
+        assert!(budget > 0, "Duplex::with_coop_budget: budget must be greater than 0");
+        Self {
+            reader,
+            writer,
+            coop: Some(Coop { budget, count: 0 }),
+        }
     }
 
     /// Decomposes a duplex into its components.
     pub fn into_inner(self) -> (R, W) {
         (self.reader, self.writer)
     }
+
+    /// Returns a reference to the reader.
+    pub fn reader(&self) -> &R {
+        &self.reader
+    }
+
+    /// Returns a reference to the writer.
+    pub fn writer(&self) -> &W {
+        &self.writer
+    }
+
+    /// Returns a mutable reference to the reader.
+    pub fn reader_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Returns a mutable reference to the writer.
+    pub fn writer_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Returns a pinned mutable reference to the reader.
+    pub fn reader_pin_mut(self: Pin<&mut Self>) -> Pin<&mut R> {
+        self.project().reader
+    }
+
+    /// Returns a pinned mutable reference to the writer.
+    pub fn writer_pin_mut(self: Pin<&mut Self>) -> Pin<&mut W> {
+        self.project().writer
+    }
 }
 
 impl<R: Read, W> Read for Duplex<R, W> {
@@ -74,7 +202,18 @@ impl<R: Read, W> Read for Duplex<R, W> {
         buf: &mut [u8],
     ) -> Poll<io::Result<usize>> {
         let this = self.project();
-        this.reader.poll_read(cx, buf)
+        if let Some(coop) = this.coop {
+            if coop.should_yield(cx) {
+                return Poll::Pending;
+            }
+        }
+        let res = this.reader.poll_read(cx, buf);
+        if let Poll::Ready(Ok(_)) = &res {
+            if let Some(coop) = this.coop {
+                coop.record();
+            }
+        }
+        res
     }
 }
 
@@ -85,7 +224,18 @@ impl<R, W: Write> Write for Duplex<R, W> {
         buf: &[u8],
     ) -> Poll<io::Result<usize>> {
         let this = self.project();
-        this.writer.poll_write(cx, buf)
+        if let Some(coop) = this.coop {
+            if coop.should_yield(cx) {
+                return Poll::Pending;
+            }
+        }
+        let res = this.writer.poll_write(cx, buf);
+        if let Poll::Ready(Ok(_)) = &res {
+            if let Some(coop) = this.coop {
+                coop.record();
+            }
+        }
+        res
     }
 
     fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
@@ -119,6 +269,7 @@ where
         Self {
             reader: self.reader.clone(),
             writer: self.writer.clone(),
+            coop: self.coop.clone(),
         }
     }
 }
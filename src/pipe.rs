@@ -0,0 +1,195 @@
+//! An in-memory, connected pair of duplex ends.
+
+use async_std::io::{self, Read, Write};
+use async_std::task::{Context, Poll, Waker};
+use std::collections::VecDeque;
+use std::fmt;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+use crate::Duplex;
+
+/// A single direction of an in-memory pipe: a fixed-capacity ring buffer
+/// shared between one [`PipeReader`] and one [`PipeWriter`].
+struct Channel {
+    buf: VecDeque<u8>,
+    capacity: usize,
+    /// The writer has been dropped or closed; the reader should drain
+    /// whatever remains and then report EOF.
+    write_closed: bool,
+    /// The reader has been dropped; the writer should fail with
+    /// `BrokenPipe` instead of buffering bytes nobody will read.
+    read_closed: bool,
+    read_waker: Option<Waker>,
+    write_waker: Option<Waker>,
+}
+
+impl Channel {
+    fn new(capacity: usize) -> Self {
+        Self {
+            buf: VecDeque::with_capacity(capacity),
+            capacity,
+            write_closed: false,
+            read_closed: false,
+            read_waker: None,
+            write_waker: None,
+        }
+    }
+}
+
+/// The read half of an in-memory pipe created by [`Duplex::pipe`].
+pub struct PipeReader(Arc<Mutex<Channel>>);
+
+/// The write half of an in-memory pipe created by [`Duplex::pipe`].
+pub struct PipeWriter(Arc<Mutex<Channel>>);
+
+impl fmt::Debug for PipeReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PipeReader").finish()
+    }
+}
+
+impl fmt::Debug for PipeWriter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PipeWriter").finish()
+    }
+}
+
+impl Read for PipeReader {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+        let mut chan = self.0.lock().unwrap();
+        if chan.buf.is_empty() {
+            if chan.write_closed {
+                return Poll::Ready(Ok(0));
+            }
+            chan.read_waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+        let n = std::cmp::min(buf.len(), chan.buf.len());
+        for slot in &mut buf[..n] {
+            *slot = chan.buf.pop_front().unwrap();
+        }
+        if let Some(waker) = chan.write_waker.take() {
+            waker.wake();
+        }
+        Poll::Ready(Ok(n))
+    }
+}
+
+impl Drop for PipeReader {
+    fn drop(&mut self) {
+        let mut chan = self.0.lock().unwrap();
+        chan.read_closed = true;
+        if let Some(waker) = chan.write_waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl Write for PipeWriter {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+        let mut chan = self.0.lock().unwrap();
+        if chan.read_closed {
+            return Poll::Ready(Err(io::Error::from(io::ErrorKind::BrokenPipe)));
+        }
+        let free = chan.capacity - chan.buf.len();
+        if free == 0 {
+            chan.write_waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+        let n = std::cmp::min(free, buf.len());
+        chan.buf.extend(&buf[..n]);
+        if let Some(waker) = chan.read_waker.take() {
+            waker.wake();
+        }
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let mut chan = self.0.lock().unwrap();
+        chan.write_closed = true;
+        if let Some(waker) = chan.read_waker.take() {
+            waker.wake();
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl Drop for PipeWriter {
+    fn drop(&mut self) {
+        let mut chan = self.0.lock().unwrap();
+        chan.write_closed = true;
+        if let Some(waker) = chan.read_waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl Duplex<PipeReader, PipeWriter> {
+    /// Create a pair of connected, in-memory `Duplex` ends.
+    ///
+    /// Bytes written to one end become readable on the other, much like
+    /// [`async_std::os::unix::net::UnixStream::pair`] but without touching
+    /// the OS. Each direction is backed by its own ring buffer of `capacity`
+    /// bytes; a write copies as many bytes as fit in the free space and
+    /// waits only when the buffer is full, and a read on an empty buffer
+    /// waits until the peer writes.
+    ///
+    /// Dropping (or closing) one end lets its peer drain whatever bytes are
+    /// still buffered and then observe EOF (`Ok(0)`), while any write aimed
+    /// at a dropped peer fails with [`ErrorKind::BrokenPipe`].
+    ///
+    /// [`ErrorKind::BrokenPipe`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.BrokenPipe
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is `0`: a zero-capacity ring buffer can never
+    /// have free space for a write, so `poll_write` would return `Pending`
+    /// forever with no byte ever read to unstick it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> std::io::Result<()> { async_std::task::block_on(async {
+    /// #
+    /// use async_std::io::prelude::*;
+    /// use duplexify::Duplex;
+    ///
+    /// let (mut a, mut b) = Duplex::pipe(64);
+    /// a.write_all(b"hello").await?;
+    /// let mut buf = [0; 5];
+    /// b.read_exact(&mut buf).await?;
+    /// assert_eq!(&buf, b"hello");
+    /// #
+    /// # Ok(()) }) }
+    /// ```
+    pub fn pipe(capacity: usize) -> (Self, Self) {
+        assert!(capacity > 0, "Duplex::pipe: capacity must be greater than 0");
+
+        let a_to_b = Arc::new(Mutex::new(Channel::new(capacity)));
+        let b_to_a = Arc::new(Mutex::new(Channel::new(capacity)));
+
+        let a = Duplex::new(PipeReader(b_to_a.clone()), PipeWriter(a_to_b.clone()));
+        let b = Duplex::new(PipeReader(a_to_b), PipeWriter(b_to_a));
+
+        (a, b)
+    }
+}
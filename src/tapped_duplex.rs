@@ -0,0 +1,124 @@
+//! A `Duplex` that records the bytes flowing through it.
+
+use async_std::io::{self, BufRead, Read, Write};
+use async_std::task::{Context, Poll};
+use std::pin::Pin;
+
+use crate::Duplex;
+
+pin_project_lite::pin_project! {
+    /// A [`Duplex`] that transparently captures the bytes it reads and
+    /// writes, for debugging and protocol testing.
+    ///
+    /// Only bytes read through `poll_read` are captured; bytes consumed
+    /// through the `BufRead` methods (`poll_fill_buf`/`consume`) are not
+    /// recorded in the read log.
+    #[derive(Debug)]
+    pub struct TappedDuplex<R, W> {
+        #[pin]
+        inner: Duplex<R, W>,
+        read_log: Vec<u8>,
+        write_log: Vec<u8>,
+    }
+}
+
+impl<R, W> TappedDuplex<R, W> {
+    /// Wrap a reader and writer in a `TappedDuplex`, capturing bytes flowing
+    /// through both sides.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> std::io::Result<()> { async_std::task::block_on(async {
+    /// #
+    /// use async_std::io::prelude::*;
+    /// use duplexify::TappedDuplex;
+    ///
+    /// let (a, _b) = duplexify::Duplex::pipe(64);
+    /// let (reader, writer) = a.into_inner();
+    /// let mut tapped = TappedDuplex::new(reader, writer);
+    /// tapped.write_all(b"hello").await?;
+    /// assert_eq!(tapped.write_log(), b"hello");
+    /// #
+    /// # Ok(()) }) }
+    /// ```
+    pub fn new(reader: R, writer: W) -> Self {
+        Self {
+            inner: Duplex::new(reader, writer),
+            read_log: Vec::new(),
+            write_log: Vec::new(),
+        }
+    }
+
+    /// Returns the bytes read through this duplex so far.
+    pub fn read_log(&self) -> &[u8] {
+        &self.read_log
+    }
+
+    /// Returns the bytes written through this duplex so far.
+    pub fn write_log(&self) -> &[u8] {
+        &self.write_log
+    }
+
+    /// Drains and returns the `(read_log, write_log)` captured so far,
+    /// leaving both logs empty.
+    pub fn take_logs(&mut self) -> (Vec<u8>, Vec<u8>) {
+        (
+            std::mem::take(&mut self.read_log),
+            std::mem::take(&mut self.write_log),
+        )
+    }
+
+    /// Decomposes a `TappedDuplex` into its reader and writer, discarding
+    /// the captured logs.
+    pub fn into_inner(self) -> (R, W) {
+        self.inner.into_inner()
+    }
+}
+
+impl<R: Read, W> Read for TappedDuplex<R, W> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.project();
+        let res = this.inner.poll_read(cx, buf);
+        if let Poll::Ready(Ok(n)) = &res {
+            this.read_log.extend_from_slice(&buf[..*n]);
+        }
+        res
+    }
+}
+
+impl<R: BufRead, W> BufRead for TappedDuplex<R, W> {
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        self.project().inner.poll_fill_buf(cx)
+    }
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        self.project().inner.consume(amt)
+    }
+}
+
+impl<R, W: Write> Write for TappedDuplex<R, W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.project();
+        let res = this.inner.poll_write(cx, buf);
+        if let Poll::Ready(Ok(n)) = &res {
+            this.write_log.extend_from_slice(&buf[..*n]);
+        }
+        res
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.project().inner.poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.project().inner.poll_close(cx)
+    }
+}